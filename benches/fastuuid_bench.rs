@@ -28,6 +28,9 @@ fn criterion_benchmark(c: &mut Criterion) {
         b.iter(|| g.hex128_as_string().unwrap())
     });
 
+    #[cfg(feature = "hash")]
+    c.bench_function("next_opaque", |b| b.iter(|| g.next_opaque()));
+
     c.bench_function("uuid_uuidV4", |b| {
         b.iter(|| {
             let mut buffer: [u8; 36] = [0; 36];
@@ -0,0 +1,142 @@
+//! Output layouts for [`Generator::format_as_str`], mirroring the
+//! `Simple`/`Hyphenated`/`Braced`/`Urn` adapters the `uuid` crate exposes.
+
+use crate::Generator;
+use std::error::Error;
+use std::fmt;
+
+/// Hex128Format selects the textual layout produced by
+/// [`Generator::format_as_str`] and [`Generator::format_as_string`]. Each
+/// variant declares the exact buffer length it needs via [`buffer_len`](Hex128Format::buffer_len).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hex128Format {
+    /// 32 lowercase hex chars, no hyphens: `11febf98c1084383bb1e739ffcd44341`.
+    Simple,
+    /// 32 uppercase hex chars, no hyphens.
+    SimpleUpper,
+    /// 36-char hyphenated, lowercase: `11febf98-c108-4383-bb1e-739ffcd44341`.
+    Hyphenated,
+    /// 36-char hyphenated, uppercase.
+    HyphenatedUpper,
+    /// Hyphenated, wrapped in braces: `{11febf98-c108-4383-bb1e-739ffcd44341}`.
+    Braced,
+    /// Braced, uppercase.
+    BracedUpper,
+    /// URN form: `urn:uuid:11febf98-c108-4383-bb1e-739ffcd44341`.
+    Urn,
+    /// URN form, uppercase.
+    UrnUpper,
+}
+
+impl Hex128Format {
+    /// Returns the exact buffer length this format requires.
+    pub fn buffer_len(self) -> usize {
+        match self {
+            Hex128Format::Simple | Hex128Format::SimpleUpper => 32,
+            Hex128Format::Hyphenated | Hex128Format::HyphenatedUpper => 36,
+            Hex128Format::Braced | Hex128Format::BracedUpper => 38,
+            Hex128Format::Urn | Hex128Format::UrnUpper => 45,
+        }
+    }
+
+    fn is_upper(self) -> bool {
+        matches!(
+            self,
+            Hex128Format::SimpleUpper
+                | Hex128Format::HyphenatedUpper
+                | Hex128Format::BracedUpper
+                | Hex128Format::UrnUpper
+        )
+    }
+
+    // write encodes `payload` into `out` (which must be exactly
+    // `self.buffer_len()` bytes) and returns the written slice.
+    fn write<'a>(self, payload: &[u8; 16], out: &'a mut [u8]) -> &'a [u8] {
+        match self {
+            Hex128Format::Simple | Hex128Format::SimpleUpper => {
+                faster_hex::hex_encode(payload, out).unwrap();
+            }
+            Hex128Format::Hyphenated | Hex128Format::HyphenatedUpper => {
+                let mut hyphenated: [u8; 36] = [0; 36];
+                Generator::format_hyphenated(payload, &mut hyphenated);
+                out.copy_from_slice(&hyphenated);
+            }
+            Hex128Format::Braced | Hex128Format::BracedUpper => {
+                let mut hyphenated: [u8; 36] = [0; 36];
+                Generator::format_hyphenated(payload, &mut hyphenated);
+                out[0] = b'{';
+                out[1..37].copy_from_slice(&hyphenated);
+                out[37] = b'}';
+            }
+            Hex128Format::Urn | Hex128Format::UrnUpper => {
+                let mut hyphenated: [u8; 36] = [0; 36];
+                Generator::format_hyphenated(payload, &mut hyphenated);
+                out[..9].copy_from_slice(b"urn:uuid:");
+                out[9..45].copy_from_slice(&hyphenated);
+            }
+        }
+
+        if self.is_upper() {
+            // Only the hex digits change case; the "urn:uuid:" prefix stays lowercase.
+            let start = if self == Hex128Format::UrnUpper { 9 } else { 0 };
+            for b in out[start..].iter_mut() {
+                if (b'a'..=b'f').contains(b) {
+                    *b -= 32;
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// Errors returned by [`Generator::format_as_str`] and
+/// [`Generator::format_as_string`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum FormatError {
+    /// The supplied buffer's length didn't match `fmt.buffer_len()`.
+    BadBufferLen { expected: usize, actual: usize },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::BadBufferLen { expected, actual } => write!(
+                f,
+                "buffer must be exactly {} bytes for this format, got {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+impl Generator {
+    /// format_as_str writes the next UUID into `buffer` using the given
+    /// format and returns it as a `&str`. `buffer` must be exactly
+    /// `fmt.buffer_len()` bytes long.
+    pub fn format_as_str<'a>(
+        &self,
+        fmt: Hex128Format,
+        buffer: &'a mut [u8],
+    ) -> Result<&'a str, Box<dyn Error>> {
+        if buffer.len() != fmt.buffer_len() {
+            return Err(Box::new(FormatError::BadBufferLen {
+                expected: fmt.buffer_len(),
+                actual: buffer.len(),
+            }));
+        }
+        let payload = Generator::masked_layout(&self.next());
+        match std::str::from_utf8(fmt.write(&payload, buffer)) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(Box::new(err)),
+        }
+    }
+
+    /// format_as_string is the allocating counterpart of `format_as_str`.
+    pub fn format_as_string(&self, fmt: Hex128Format) -> Result<String, Box<dyn Error>> {
+        let mut buffer = vec![0u8; fmt.buffer_len()];
+        self.format_as_str(fmt, &mut buffer).map(|s| s.to_owned())
+    }
+}
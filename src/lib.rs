@@ -47,14 +47,129 @@
 //!
 //!Note: there is also an unsafe version of both functions, which uses unsafe cast to string from utf8, making them a bit faster.
 //!It is ok to use all of those concurrently.
+//!
+//!## Crate features
+//!
+//!* `std` (default) - enables `Generator::new`, the allocating `*_as_string`
+//!  variants, and the `format`/`serde` support below. Without it the crate is
+//!  `no_std` and only `Generator::from_seed`, `next`, `iter`, `fill`,
+//!  `next_uuid128`, `hex128_as_str`, `hex128_as_str_unchecked`,
+//!  `is_valid_hex128`, and `parse_hex128` are available.
+//!* `serde` - `Serialize`/`Deserialize` for `Uuid128` (requires `std`).
+//!* `hash` - `Generator::next_opaque`, which hashes the sequential payload
+//!  with a key derived from the generator's seed so adjacent IDs are no
+//!  longer predictable (`next_opaque_as_string` additionally requires `std`).
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate faster_hex;
 extern crate rand;
+#[cfg(feature = "hash")]
+extern crate sha2;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(all(feature = "serde", feature = "std"))]
+mod serde_support;
+#[cfg(feature = "std")]
+mod format;
+
+#[cfg(feature = "std")]
+pub use format::{FormatError, Hex128Format};
 
+#[cfg(feature = "std")]
 use rand::Rng;
-use std::convert::TryInto;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use core::convert::TryInto;
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Errors returned by [`Generator::parse_hex128`] when a string does not
+/// conform to the hyphenated 128-bit hex layout produced by `hex128_from_bytes`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string's length was not the expected 36 bytes.
+    BadLength(usize),
+    /// The byte at the given position was expected to be a hyphen.
+    MisplacedHyphen(usize),
+    /// A non-hex-digit byte was found in the hex group starting at the given offset.
+    InvalidHexDigit(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::BadLength(len) => {
+                write!(f, "expected a 36-byte hyphenated UUID, got {} bytes", len)
+            }
+            ParseError::MisplacedHyphen(pos) => write!(f, "expected a hyphen at position {}", pos),
+            ParseError::InvalidHexDigit(pos) => {
+                write!(f, "invalid hex digit in the group starting at position {}", pos)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseError {}
+
+/// Error returned by [`Generator::hex128_as_str`] and
+/// [`Generator::hex128_as_string`]. A concrete, allocation-free type (rather
+/// than `Box<dyn Error>`) so the `no_std` build doesn't need an allocator.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Hex128Error {
+    /// The hex-encoded bytes were not valid UTF-8 (practically unreachable,
+    /// since hex digits and hyphens are always valid ASCII).
+    Utf8,
+}
+
+impl fmt::Display for Hex128Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hex128Error::Utf8 => write!(f, "hex-encoded UUID was not valid UTF-8"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Hex128Error {}
+
+/// Uuid128 is the 16-byte RFC4122 V4 payload backing the hyphenated strings
+/// returned by `hex128_as_str` and friends. It is returned by
+/// [`Generator::next_uuid128`] for callers who want a serde-ready value
+/// without juggling `[u8; 36]` string buffers.
+///
+/// Enable the `serde` cargo feature to (de)serialize it as the hyphenated
+/// hex string in human-readable formats (JSON, ...) or as raw bytes in
+/// compact/binary ones (bincode, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uuid128(pub(crate) [u8; 16]);
+
+impl From<[u8; 16]> for Uuid128 {
+    fn from(bytes: [u8; 16]) -> Uuid128 {
+        Uuid128(bytes)
+    }
+}
+
+impl Uuid128 {
+    /// Returns the raw 16-byte payload.
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Returns the hyphenated hex128 string representation, e.g.
+    /// `11febf98-c108-4383-bb1e-739ffcd44341`.
+    #[cfg(feature = "std")]
+    pub fn hex128_as_string(&self) -> String {
+        let mut buffer: [u8; 36] = [0; 36];
+        core::str::from_utf8(Generator::format_hyphenated(&self.0, &mut buffer))
+            .expect("hex-encoded UUID is always valid UTF-8")
+            .to_owned()
+    }
+}
 
 // Generator is a uuid generator that generates unique and guessable 192-bit UUIDs, starting from a random sequence.
 pub struct Generator {
@@ -62,12 +177,95 @@ pub struct Generator {
     // the first 8 bytes are stored in the counter and used for generating new UUIDs
     seed: [u8; 24],
     counter: AtomicUsize,
+    // Per-generator key used to key next_opaque's hash, derived in from_seed
+    // as the SHA-256 of the seed, so that generators with different seeds
+    // produce different opaque UUIDs for the same counter value.
+    #[cfg(feature = "hash")]
+    hash_key: [u8; 32],
 }
 
+#[cfg(feature = "std")]
 impl Generator {
     #[allow(dead_code)]
     pub fn new() -> Generator {
         let seed = rand::thread_rng().gen::<[u8; 24]>();
+        Generator::from_seed(seed)
+    }
+
+    // next_v7 returns a time-ordered, database-friendly UUID following the
+    // UUIDv7 layout: a 48-bit big-endian Unix millisecond timestamp, the
+    // version/variant bits, and 74 bits of free space filled with the
+    // generator's monotonic counter (plus a sliver of its seed) so that IDs
+    // minted within the same millisecond stay strictly increasing.
+    pub fn next_v7(&self) -> [u8; 16] {
+        let raw = self.next();
+        let counter = u64::from_le_bytes(raw[0..8].try_into().unwrap());
+        // Low 10 bits of free space come from the seed, so generators that
+        // happen to share a counter value and millisecond still differ.
+        let seed_tail = ((raw[8] as u128) << 2) | (raw[9] as u128 >> 6);
+        let free: u128 = ((counter as u128) << 10) | (seed_tail & 0x3ff);
+
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        let uuid: u128 = ((millis & 0xFFFF_FFFF_FFFFu128) << 80)
+            | (0x7u128 << 76)
+            | (((free >> 62) & 0xFFF) << 64)
+            | (0b10u128 << 62)
+            | (free & 0x3FFF_FFFF_FFFF_FFFF);
+
+        uuid.to_be_bytes()
+    }
+
+    // next_v7_as_string returns the hyphenated string form of next_v7,
+    // reusing the crate's existing formatting path.
+    pub fn next_v7_as_string(&self) -> String {
+        let mut buffer: [u8; 36] = [0; 36];
+        core::str::from_utf8(Generator::format_hyphenated(&self.next_v7(), &mut buffer))
+            .expect("hex-encoded UUID is always valid UTF-8")
+            .to_owned()
+    }
+}
+
+#[cfg(feature = "hash")]
+impl Generator {
+    // next_opaque hashes the sequential payload from next() with a
+    // per-generator key (SHA-256 of hash_key || next(), i.e. the
+    // little-endian counter followed by the seed tail, truncated to 16
+    // bytes), so the result stays unique -- the counter
+    // still guarantees distinct inputs -- but adjacent IDs are no longer
+    // predictable like the plain hex128 path. See the `next_opaque` entry
+    // in the benchmark suite for the throughput cost relative to `next`.
+    pub fn next_opaque(&self) -> [u8; 16] {
+        let mut hasher = <sha2::Sha256 as sha2::Digest>::new();
+        sha2::Digest::update(&mut hasher, self.hash_key);
+        sha2::Digest::update(&mut hasher, self.next());
+        let digest = sha2::Digest::finalize(hasher);
+
+        let mut payload: [u8; 16] = [0; 16];
+        payload.copy_from_slice(&digest[0..16]);
+        Generator::set_version_variant(&mut payload);
+        payload
+    }
+
+    // next_opaque_as_string returns the hyphenated string form of
+    // next_opaque, reusing the crate's existing formatting path.
+    #[cfg(feature = "std")]
+    pub fn next_opaque_as_string(&self) -> String {
+        let mut buffer: [u8; 36] = [0; 36];
+        core::str::from_utf8(Generator::format_hyphenated(&self.next_opaque(), &mut buffer))
+            .expect("hex-encoded UUID is always valid UTF-8")
+            .to_owned()
+    }
+}
+
+impl Generator {
+    // from_seed constructs a deterministic Generator from a fixed 24-byte
+    // seed, bypassing thread_rng so tests and fixtures can pin the output.
+    // Generator::new samples a random seed and delegates here.
+    pub fn from_seed(seed: [u8; 24]) -> Generator {
         Generator {
             seed,
             counter: AtomicUsize::new(
@@ -75,48 +273,85 @@ impl Generator {
                     .try_into()
                     .unwrap(),
             ),
+            #[cfg(feature = "hash")]
+            hash_key: Generator::derive_hash_key(&seed),
         }
     }
 
+    #[cfg(feature = "hash")]
+    fn derive_hash_key(seed: &[u8; 24]) -> [u8; 32] {
+        let mut key: [u8; 32] = [0; 32];
+        key.copy_from_slice(&<sha2::Sha256 as sha2::Digest>::digest(seed));
+        key
+    }
+
+    // build assembles the UUID for a given counter value: the first 8 bytes
+    // hold current (little-endian), the rest is the constant seed tail.
+    fn build(&self, current: usize) -> [u8; 24] {
+        let mut uuid: [u8; 24] = Default::default();
+        uuid[..8].copy_from_slice(&current.to_le_bytes());
+        uuid[8..].copy_from_slice(&self.seed[8..]);
+        uuid
+    }
+
     // Next returns the next UUID from the generator.
     // Only the first 8 bytes differ from the previous one.
     // It can be used concurrently.
     pub fn next(&self) -> [u8; 24] {
         let current = self.counter.fetch_add(1, Ordering::SeqCst);
-        let mut uuid: [u8; 24] = Default::default();
-        uuid[..8].copy_from_slice(&current.to_le_bytes());
-        uuid[8..].copy_from_slice(&self.seed[8..]);
-        return uuid;
+        self.build(current)
     }
 
-    // hex128_as_str returns hex128(Generator::next()) as &str (without heap allocation of the result)
-    pub fn hex128_as_str<'a>(&self, buffer: &'a mut [u8; 36]) -> Result<&'a str, Box<dyn Error>> {
-        match std::str::from_utf8(Generator::hex128_from_bytes(&self.next(), buffer)) {
-            Ok(res) => Ok(res),
-            Err(err) => Err(Box::new(err)),
+    // iter returns an unbounded iterator over next()'s 24-byte stream,
+    // advancing the generator's counter by one per item.
+    pub fn iter(&self) -> impl Iterator<Item = [u8; 24]> + '_ {
+        core::iter::from_fn(move || Some(self.next()))
+    }
+
+    // fill advances the counter once by out.len() and writes a contiguous
+    // block of UUIDs into out, amortizing the fetch-add for high-volume
+    // generation.
+    pub fn fill(&self, out: &mut [[u8; 24]]) {
+        let start = self.counter.fetch_add(out.len(), Ordering::SeqCst);
+        for (i, slot) in out.iter_mut().enumerate() {
+            *slot = self.build(start + i);
         }
     }
 
+    // hex128_as_str returns hex128(Generator::next()) as &str (without heap allocation of the result)
+    // Available under no_std: it only needs core.
+    pub fn hex128_as_str<'a>(&self, buffer: &'a mut [u8; 36]) -> Result<&'a str, Hex128Error> {
+        core::str::from_utf8(Generator::hex128_from_bytes(&self.next(), buffer))
+            .map_err(|_| Hex128Error::Utf8)
+    }
+
     // hex128_as_str_unchecked returns hex128(Generator::next()) as &str (without heap allocation of the result)
-    // Uses unsafe cast to string from utf8
+    // Uses unsafe cast to string from utf8. Available under no_std: it only needs core.
     pub unsafe fn hex128_as_str_unchecked<'a>(&self, buffer: &'a mut [u8; 36]) -> &'a str {
-        std::str::from_utf8_unchecked(Generator::hex128_from_bytes(&self.next(), buffer))
+        core::str::from_utf8_unchecked(Generator::hex128_from_bytes(&self.next(), buffer))
     }
 
     // hex128_as_string returns hex128(Generator::next()) as boxed String value
+    #[cfg(feature = "std")]
     pub unsafe fn hex128_as_string_unchecked(&self) -> String {
         let mut buffer: [u8; 36] = [0; 36];
-        std::str::from_utf8_unchecked(Generator::hex128_from_bytes(&self.next(), &mut buffer))
+        core::str::from_utf8_unchecked(Generator::hex128_from_bytes(&self.next(), &mut buffer))
             .to_owned()
     }
 
     // hex128_as_string returns hex128(Generator::next()) as boxed String value
-    pub fn hex128_as_string(&self) -> Result<String, Box<dyn Error>> {
+    #[cfg(feature = "std")]
+    pub fn hex128_as_string(&self) -> Result<String, Hex128Error> {
         let mut buffer: [u8; 36] = [0; 36];
-        match std::str::from_utf8(Generator::hex128_from_bytes(&self.next(), &mut buffer)) {
-            Ok(res) => Ok(res.to_owned()),
-            Err(err) => Err(Box::new(err)),
-        }
+        core::str::from_utf8(Generator::hex128_from_bytes(&self.next(), &mut buffer))
+            .map(|s| s.to_owned())
+            .map_err(|_| Hex128Error::Utf8)
+    }
+
+    // next_uuid128 returns the next UUID as a serde-ready Uuid128 value,
+    // skipping the intermediate [u8; 36] string buffer entirely.
+    pub fn next_uuid128(&self) -> Uuid128 {
+        Uuid128(Generator::masked_layout(&self.next()))
     }
 
     // Hex128 returns an RFC4122 V4 representation of the
@@ -132,16 +367,37 @@ impl Generator {
     // hashing the uuid (using SHA256, for example) before passing it
     // to Hex128.
     fn hex128_from_bytes<'a>(uuid: &[u8; 24], buffer: &'a mut [u8; 36]) -> &'a [u8] {
+        Generator::format_hyphenated(&Generator::masked_layout(uuid), buffer)
+    }
+
+    // masked_layout swaps bytes 6 and 9 of the given UUID so that all the
+    // varying bits of Generator::next() are reflected in the first 16 bytes,
+    // then forces the V4 version nibble and RFC4122 variant bits onto that
+    // 16-byte payload.
+    pub(crate) fn masked_layout(uuid: &[u8; 24]) -> [u8; 16] {
         let mut temp_uuid: [u8; 24] = [0; 24];
         temp_uuid.copy_from_slice(uuid);
         temp_uuid.swap(6, 9);
 
+        let mut payload: [u8; 16] = [0; 16];
+        payload.copy_from_slice(&temp_uuid[0..16]);
+        Generator::set_version_variant(&mut payload);
+        payload
+    }
+
+    // set_version_variant forces the V4 version nibble and RFC4122 variant
+    // bits onto a 16-byte UUID payload, in place.
+    fn set_version_variant(payload: &mut [u8; 16]) {
         // V4
-        temp_uuid[6] = (temp_uuid[6] & 0x0f) | 0x40;
+        payload[6] = (payload[6] & 0x0f) | 0x40;
         // RFC4122
-        temp_uuid[8] = temp_uuid[8] & 0x3f | 0x80;
+        payload[8] = payload[8] & 0x3f | 0x80;
+    }
 
-        faster_hex::hex_encode(&temp_uuid[0..16], &mut buffer[0..32]).unwrap();
+    // format_hyphenated writes the hex128 hyphenated encoding of a 16-byte
+    // RFC4122 payload into buffer, e.g. 11febf98-c108-4383-bb1e-739ffcd44341.
+    pub(crate) fn format_hyphenated<'a>(payload: &[u8; 16], buffer: &'a mut [u8; 36]) -> &'a [u8] {
+        faster_hex::hex_encode(payload, &mut buffer[0..32]).unwrap();
         buffer.copy_within(20..32, 24); // needs rust stable 1.37.0!!
         buffer.copy_within(16..20, 19);
         buffer.copy_within(12..16, 14);
@@ -177,14 +433,57 @@ impl Generator {
         hex.chars()
             .all(|c| '0' <= c && c <= '9' || 'a' <= c && c <= 'f')
     }
+
+    // parse_hex128 is the inverse of hex128_from_bytes: it validates the
+    // 36-char hyphenated shape and decodes the five hex groups back into 16
+    // bytes. masked_layout's swap(6, 9) already happens before encoding, so
+    // the hyphenated string's byte order matches the final 16-byte payload
+    // as-is -- no further permutation is needed on the way back in.
+    //
+    // Note: hex128_from_bytes forces the version nibble and variant bits
+    // before encoding, so bytes 6 and 8 of the returned array only
+    // round-trip their masked form, not necessarily the original input.
+    pub fn parse_hex128(s: &str) -> Result<[u8; 16], ParseError> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 36 {
+            return Err(ParseError::BadLength(bytes.len()));
+        }
+        for &pos in &[8, 13, 18, 23] {
+            if bytes[pos] != b'-' {
+                return Err(ParseError::MisplacedHyphen(pos));
+            }
+        }
+        for &(start, end) in &[(0, 8), (9, 13), (14, 18), (19, 23), (24, 36)] {
+            if !Generator::valid_hex(&s[start..end]) {
+                return Err(ParseError::InvalidHexDigit(start));
+            }
+        }
+
+        let mut hex: [u8; 32] = [0; 32];
+        hex[0..8].copy_from_slice(&bytes[0..8]);
+        hex[8..12].copy_from_slice(&bytes[9..13]);
+        hex[12..16].copy_from_slice(&bytes[14..18]);
+        hex[16..20].copy_from_slice(&bytes[19..23]);
+        hex[20..32].copy_from_slice(&bytes[24..36]);
+
+        let mut decoded: [u8; 16] = [0; 16];
+        faster_hex::hex_decode(&hex, &mut decoded).expect("hex digits already validated");
+        Ok(decoded)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Generator;
+    use crate::{Generator, Hex128Format, ParseError};
     use std::thread;
     use std::collections::HashMap;
     use std::sync::{RwLock, Arc};
+    #[cfg(feature = "serde")]
+    use crate::Uuid128;
+    #[cfg(feature = "serde")]
+    extern crate bincode;
+    #[cfg(feature = "serde")]
+    extern crate serde_json;
 
     #[test]
     fn next() {
@@ -284,4 +583,168 @@ mod tests {
             "should be invalid hex"
         );
     }
+
+    #[test]
+    fn format_as_str_shapes() {
+        let generator = Generator::new();
+
+        let mut simple: [u8; 32] = [0; 32];
+        let simple = generator.format_as_str(Hex128Format::Simple, &mut simple).unwrap();
+        assert_eq!(simple.len(), 32);
+        assert!(!simple.contains('-'));
+
+        let mut hyphenated: [u8; 36] = [0; 36];
+        let hyphenated = generator
+            .format_as_str(Hex128Format::Hyphenated, &mut hyphenated)
+            .unwrap();
+        assert!(Generator::is_valid_hex128(hyphenated));
+
+        let mut braced: [u8; 38] = [0; 38];
+        let braced = generator.format_as_str(Hex128Format::Braced, &mut braced).unwrap();
+        assert!(braced.starts_with('{') && braced.ends_with('}'));
+
+        let mut urn: [u8; 45] = [0; 45];
+        let urn = generator.format_as_str(Hex128Format::Urn, &mut urn).unwrap();
+        assert!(urn.starts_with("urn:uuid:"));
+
+        let mut upper: [u8; 36] = [0; 36];
+        let upper = generator
+            .format_as_str(Hex128Format::HyphenatedUpper, &mut upper)
+            .unwrap();
+        assert_eq!(upper, upper.to_uppercase());
+    }
+
+    #[test]
+    fn format_as_str_wrong_buffer_len() {
+        let generator = Generator::new();
+        let mut buffer: [u8; 10] = [0; 10];
+        assert!(generator
+            .format_as_str(Hex128Format::Hyphenated, &mut buffer)
+            .is_err());
+    }
+
+    #[test]
+    fn next_v7_is_valid_and_monotonic() {
+        let generator = Generator::new();
+        let mut previous = generator.next_v7();
+
+        for _ in 0..1000 {
+            let current = generator.next_v7();
+            assert_eq!(current[6] & 0xf0, 0x70, "version nibble should be 0x7");
+            assert_eq!(current[8] & 0xc0, 0x80, "variant bits should be 0b10");
+            assert!(current > previous, "v7 UUIDs should be strictly increasing");
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn from_seed_is_deterministic() {
+        let seed: [u8; 24] = [7; 24];
+        let a = Generator::from_seed(seed);
+        let b = Generator::from_seed(seed);
+
+        for _ in 0..10 {
+            assert_eq!(a.next(), b.next(), "same seed should produce the same stream");
+        }
+    }
+
+    #[test]
+    fn iter_matches_next() {
+        let seed: [u8; 24] = [3; 24];
+        let via_iter = Generator::from_seed(seed);
+        let via_next = Generator::from_seed(seed);
+
+        let collected: Vec<_> = via_iter.iter().take(5).collect();
+        let expected: Vec<_> = (0..5).map(|_| via_next.next()).collect();
+        assert_eq!(collected, expected);
+    }
+
+    #[test]
+    fn fill_matches_next() {
+        let seed: [u8; 24] = [9; 24];
+        let via_fill = Generator::from_seed(seed);
+        let via_next = Generator::from_seed(seed);
+
+        let mut out = [[0u8; 24]; 5];
+        via_fill.fill(&mut out);
+        for expected in out.iter() {
+            assert_eq!(*expected, via_next.next());
+        }
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn next_opaque_is_valid_and_unique() {
+        let generator = Generator::new();
+        let first = generator.next_opaque();
+        let second = generator.next_opaque();
+
+        assert_eq!(first[6] & 0xf0, 0x40, "version nibble should be 0x4");
+        assert_eq!(first[8] & 0xc0, 0x80, "variant bits should be 0b10");
+        assert_ne!(first, second, "opaque UUIDs should be unique");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn uuid128_serde_roundtrip() {
+        let generator = Generator::new();
+        let uuid = generator.next_uuid128();
+
+        // Human-readable formats (JSON) serialize to the hyphenated string.
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, format!("\"{}\"", uuid.hex128_as_string()));
+        let from_json: Uuid128 = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json, uuid);
+
+        // Compact/binary (non-self-describing) formats like bincode take
+        // the `serialize_bytes`/`deserialize_bytes` path instead of the
+        // hyphenated string.
+        let bytes = bincode::serialize(&uuid).unwrap();
+        let from_bytes: Uuid128 = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(from_bytes, uuid);
+    }
+
+    #[test]
+    fn next_uuid128() {
+        let generator = Generator::new();
+        let uuid = generator.next_uuid128();
+
+        assert!(
+            Generator::is_valid_hex128(&uuid.hex128_as_string()),
+            "should be valid hex"
+        );
+    }
+
+    #[test]
+    fn parse_hex128_roundtrip() {
+        let generator = Generator::from_seed([
+            200, 201, 202, 203, 204, 205, 206, 207, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+            20, 21, 22, 23,
+        ]);
+        let raw = generator.next();
+        let expected = Generator::masked_layout(&raw);
+
+        let mut buffer: [u8; 36] = [0; 36];
+        let uuid = core::str::from_utf8(Generator::format_hyphenated(&expected, &mut buffer))
+            .unwrap();
+
+        let decoded = Generator::parse_hex128(uuid).unwrap();
+        assert_eq!(decoded, expected, "parse_hex128 must be the exact inverse of masked_layout + format_hyphenated");
+    }
+
+    #[test]
+    fn parse_hex128_errors() {
+        assert_eq!(
+            Generator::parse_hex128("11febf98-c108-4383-bb1e-739ffcd4434"),
+            Err(ParseError::BadLength(35))
+        );
+        assert_eq!(
+            Generator::parse_hex128("11febf98c-108-4383-bb1e-739ffcd44341"),
+            Err(ParseError::MisplacedHyphen(8))
+        );
+        assert_eq!(
+            Generator::parse_hex128("zzfebf98-c108-4383-bb1e-739ffcd44341"),
+            Err(ParseError::InvalidHexDigit(0))
+        );
+    }
 }
@@ -0,0 +1,67 @@
+//! Optional `serde` support for [`Uuid128`], enabled via the `serde` cargo
+//! feature.
+//!
+//! Human-readable formats (JSON, TOML, ...) serialize to the hyphenated hex
+//! string; compact/binary formats (bincode, MessagePack, ...) serialize to
+//! the raw `[u8; 16]` payload.
+
+use crate::{Generator, Uuid128};
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryInto;
+use std::fmt;
+
+impl Serialize for Uuid128 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.hex128_as_string())
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+struct Uuid128Visitor;
+
+impl<'de> Visitor<'de> for Uuid128Visitor {
+    type Value = Uuid128;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hyphenated UUID string or a 16-byte array")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Uuid128, E>
+    where
+        E: Error,
+    {
+        Generator::parse_hex128(v)
+            .map(Uuid128::from)
+            .map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Uuid128, E>
+    where
+        E: Error,
+    {
+        let bytes: [u8; 16] = v
+            .try_into()
+            .map_err(|_| E::invalid_length(v.len(), &"16 bytes"))?;
+        Ok(Uuid128::from(bytes))
+    }
+}
+
+impl<'de> Deserialize<'de> for Uuid128 {
+    fn deserialize<D>(deserializer: D) -> Result<Uuid128, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Uuid128Visitor)
+        } else {
+            deserializer.deserialize_bytes(Uuid128Visitor)
+        }
+    }
+}